@@ -0,0 +1,5 @@
+//! A blocking (synchronous) client, for use outside of an async runtime.
+
+mod strompris;
+
+pub use strompris::Strompris;
@@ -3,10 +3,12 @@ use reqwest::blocking::Client;
 use reqwest::header::HeaderMap;
 use url::Url;
 
+use crate::DailyPrices;
+use crate::Date;
 use crate::Error;
 use crate::PriceRegion;
 use crate::Result;
-use crate::{HourlyPrice, MIN_DATE};
+use crate::{collect_range_results, date_range, HourlyPrice, MIN_DATE};
 
 /// The blocking version of [`Strompris`].
 ///
@@ -46,11 +48,13 @@ impl Strompris {
 
     /// Get the price for the given date and price region.
     ///
-    /// The prices are represented by a vector consisting of 24 hourly prices.
+    /// The prices are returned as a [`DailyPrices`], which usually wraps 24 hourly prices.
+    /// On the Norwegian DST transition days it wraps 23 (spring-forward) or 25 (fall-back)
+    /// entries instead; see [`DailyPrices::kind`] and [`DailyPrices::by_local_hour`].
     ///
     /// Note: The API does not know the future! Tomorrow's prices are usually ready by 13:00,
     /// local time.
-    pub fn get_prices(&self, date: impl Datelike, price_region: PriceRegion) -> Result<Vec<HourlyPrice>> {
+    pub fn get_prices(&self, date: impl Datelike, price_region: PriceRegion) -> Result<DailyPrices> {
         let price_region = match price_region {
             PriceRegion::NO1 => "NO1",
             PriceRegion::NO2 => "NO2",
@@ -70,10 +74,37 @@ impl Strompris {
         let url = self.base_url.join(endpoint.as_str()).unwrap();
         let response = self.client.get(url).send()?;
         if response.status().is_client_error() {
-            return Err(Error::Generic("Prices are not available for this date".to_string()));
+            return Err(Error::Unavailable);
         }
 
-        Ok(response.json::<Vec<HourlyPrice>>()?)
+        Ok(DailyPrices::new(response.json::<Vec<HourlyPrice>>()?))
+    }
+
+    /// Get the prices for every day in the inclusive range `start..=end`, one request after
+    /// another, preserving chronological order in the result.
+    ///
+    /// If `skip_unavailable` is `true`, days for which the API has no prices yet (e.g. today's
+    /// date before publication) are omitted from the result instead of failing the whole range.
+    /// Any other error fails the whole range with the earliest such error; every day in the
+    /// range is still requested before the results are checked.
+    pub fn get_prices_range(
+        &self,
+        start: impl Datelike,
+        end: impl Datelike,
+        price_region: PriceRegion,
+        skip_unavailable: bool,
+    ) -> Result<Vec<(Date, DailyPrices)>> {
+        let dates = date_range(&start, &end)?;
+
+        let results = dates
+            .into_iter()
+            .map(|date| {
+                let result = self.get_prices(date.clone(), price_region);
+                (date, result)
+            })
+            .collect();
+
+        collect_range_results(results, skip_unavailable)
     }
 
     fn date_after_min_date(&self, given_date: &impl Datelike) -> bool {
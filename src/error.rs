@@ -18,4 +18,38 @@ pub enum Error {
     /// Wrapper for url::ParseError
     #[from]
     ParseError(ParseError),
+    /// The API does not (yet) have prices for the requested date.
+    #[display(fmt = "Prices are not available for this date")]
+    Unavailable,
+}
+
+impl Error {
+    /// Whether this error means prices are not yet available for the requested date, as
+    /// opposed to a network failure, bad URL, or other unexpected error.
+    ///
+    /// Used by `get_prices_range`'s `skip_unavailable` flag to decide which errors are safe
+    /// to skip over rather than aborting the whole range.
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, Error::Unavailable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_unavailable_is_true_for_the_unavailable_variant() {
+        assert!(Error::Unavailable.is_unavailable());
+    }
+
+    #[test]
+    fn is_unavailable_is_false_for_other_variants() {
+        assert!(!Error::Generic("Date is before the minimum acceptable date".to_string()).is_unavailable());
+    }
+
+    #[test]
+    fn unavailable_displays_the_same_message_callers_previously_matched_on() {
+        assert_eq!(Error::Unavailable.to_string(), "Prices are not available for this date");
+    }
 }
@@ -6,6 +6,13 @@
 //! This crate offers both async and blocking ways of fetching prices. See the blocking module
 //! for more information on the blocking API.
 //!
+//! Enabling the `timezone` feature adds a dependency on `chrono-tz` and methods on
+//! [`HourlyPrice`] for converting its timestamps into genuine Europe/Oslo wall-clock time,
+//! resolved through the IANA tz database rather than the fixed offset baked into each payload.
+//!
+//! The [`scheduling`] module answers "when is the cheapest time to run this" questions, such
+//! as finding the cheapest consecutive block of hours for a dishwasher or EV charger.
+//!
 //! See [`www.hvakosterstrommen.no`] for more info about the API.
 //!
 //! Example using tokio:
@@ -37,14 +44,18 @@ use reqwest::Client;
 use url::Url;
 
 pub use error::Error;
+pub use models::DailyPrices;
 pub use models::Date;
+pub use models::DayKind;
 pub use models::HourlyPrice;
 pub use models::PriceRegion;
 
 pub mod blocking;
 pub mod error;
 mod local_time_deserializer;
+mod local_time_serializer;
 mod models;
+pub mod scheduling;
 
 // Has to be an option because of rustc limitations.
 static MIN_DATE: Option<NaiveDate> = NaiveDate::from_ymd_opt(2021, 12, 1);
@@ -91,11 +102,13 @@ impl Strompris {
 
     /// Get the prices for the given date and price region.
     ///
-    /// The prices are represented by a vector consisting of 24 hourly prices.
+    /// The prices are returned as a [`DailyPrices`], which usually wraps 24 hourly prices.
+    /// On the Norwegian DST transition days it wraps 23 (spring-forward) or 25 (fall-back)
+    /// entries instead; see [`DailyPrices::kind`] and [`DailyPrices::by_local_hour`].
     ///
     /// Note: The API does not know the future! Tomorrow's prices are usually ready by 13:00,
     /// local time.
-    pub async fn get_prices(&self, date: impl Datelike, price_region: PriceRegion) -> Result<Vec<HourlyPrice>> {
+    pub async fn get_prices(&self, date: impl Datelike, price_region: PriceRegion) -> Result<DailyPrices> {
         if !self.date_after_min_date(&date) {
             return Err(Error::Generic("Date is before the minimum acceptable date".into()));
         }
@@ -116,10 +129,39 @@ impl Strompris {
 
         let response = self.client.get(url).send().await?;
         if response.status().is_client_error() {
-            return Err(Error::Generic("Prices are not available for this date".to_string()));
+            return Err(Error::Unavailable);
         }
 
-        Ok(response.json::<Vec<HourlyPrice>>().await?)
+        Ok(DailyPrices::new(response.json::<Vec<HourlyPrice>>().await?))
+    }
+
+    /// Get the prices for every day in the inclusive range `start..=end`, driving up to 8
+    /// requests concurrently while preserving chronological order in the result.
+    ///
+    /// If `skip_unavailable` is `true`, days for which the API has no prices yet (e.g. today's
+    /// date before publication) are omitted from the result instead of failing the whole range.
+    /// Any other error fails the whole range with the earliest such error; every day in the
+    /// range is still requested before the results are checked.
+    pub async fn get_prices_range(
+        &self,
+        start: impl Datelike,
+        end: impl Datelike,
+        price_region: PriceRegion,
+        skip_unavailable: bool,
+    ) -> Result<Vec<(Date, DailyPrices)>> {
+        use futures::stream::{self, StreamExt};
+
+        const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+        let dates = date_range(&start, &end)?;
+
+        let results = stream::iter(dates)
+            .map(|date| async move { (date.clone(), self.get_prices(date, price_region).await) })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        collect_range_results(results, skip_unavailable)
     }
 
     fn date_after_min_date(&self, given_date: &impl Datelike) -> bool {
@@ -137,6 +179,39 @@ impl Default for Strompris {
     }
 }
 
+/// Builds the inclusive list of dates from `start` to `end`, used by `get_prices_range`.
+fn date_range(start: &impl Datelike, end: &impl Datelike) -> Result<Vec<Date>> {
+    let start = NaiveDate::from_ymd_opt(start.year(), start.month(), start.day())
+        .ok_or_else(|| Error::Generic("Invalid start date".to_string()))?;
+    let end = NaiveDate::from_ymd_opt(end.year(), end.month(), end.day())
+        .ok_or_else(|| Error::Generic("Invalid end date".to_string()))?;
+
+    let mut dates = Vec::new();
+    let mut current = start;
+    while current <= end {
+        dates.push(Date::from_ymd_opt(current.year(), current.month(), current.day()).unwrap());
+        current = current
+            .succ_opt()
+            .ok_or_else(|| Error::Generic("Date range exceeds the maximum representable date".to_string()))?;
+    }
+    Ok(dates)
+}
+
+/// Applies the `skip_unavailable` flag to a range of per-day results, preserving the
+/// chronological order they were collected in. Shared by the async and blocking
+/// `get_prices_range` implementations.
+fn collect_range_results(results: Vec<(Date, Result<DailyPrices>)>, skip_unavailable: bool) -> Result<Vec<(Date, DailyPrices)>> {
+    let mut prices = Vec::with_capacity(results.len());
+    for (date, result) in results {
+        match result {
+            Ok(daily) => prices.push((date, daily)),
+            Err(err) if skip_unavailable && err.is_unavailable() => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(prices)
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{DateTime, Utc};
@@ -234,4 +309,80 @@ mod tests {
             "Prices are not available for this date".to_string()
         );
     }
+
+    #[test]
+    fn date_range_single_day_returns_one_date() {
+        let day = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let dates = date_range(&day, &day).unwrap();
+        assert_eq!(dates, vec![Date::from_ymd_opt(2024, 1, 31).unwrap()]);
+    }
+
+    #[test]
+    fn date_range_multi_day_is_inclusive_and_chronological() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let dates = date_range(&start, &end).unwrap();
+        assert_eq!(
+            dates,
+            vec![
+                Date::from_ymd_opt(2024, 1, 30).unwrap(),
+                Date::from_ymd_opt(2024, 1, 31).unwrap(),
+                Date::from_ymd_opt(2024, 2, 1).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn date_range_errors_when_succ_opt_would_overflow() {
+        let result = date_range(&NaiveDate::MAX, &NaiveDate::MAX);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn collect_range_results_preserves_chronological_order() {
+        let day1 = Date::from_ymd_opt(2024, 1, 1).unwrap();
+        let day2 = Date::from_ymd_opt(2024, 1, 2).unwrap();
+        let results = vec![(day1.clone(), Ok(DailyPrices::default())), (day2.clone(), Ok(DailyPrices::default()))];
+
+        let prices = collect_range_results(results, false).unwrap();
+
+        assert_eq!(prices.iter().map(|(date, _)| date.clone()).collect::<Vec<_>>(), vec![day1, day2]);
+    }
+
+    #[test]
+    fn collect_range_results_skips_unavailable_days_when_flag_is_set() {
+        let day1 = Date::from_ymd_opt(2024, 1, 1).unwrap();
+        let day2 = Date::from_ymd_opt(2024, 1, 2).unwrap();
+        let day3 = Date::from_ymd_opt(2024, 1, 3).unwrap();
+        let results = vec![
+            (day1.clone(), Ok(DailyPrices::default())),
+            (day2, Err(Error::Unavailable)),
+            (day3.clone(), Ok(DailyPrices::default())),
+        ];
+
+        let prices = collect_range_results(results, true).unwrap();
+
+        assert_eq!(prices.iter().map(|(date, _)| date.clone()).collect::<Vec<_>>(), vec![day1, day3]);
+    }
+
+    #[test]
+    fn collect_range_results_aborts_on_unavailable_day_when_flag_is_unset() {
+        let day1 = Date::from_ymd_opt(2024, 1, 1).unwrap();
+        let day2 = Date::from_ymd_opt(2024, 1, 2).unwrap();
+        let results = vec![(day1, Ok(DailyPrices::default())), (day2, Err(Error::Unavailable))];
+
+        let result = collect_range_results(results, false);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn collect_range_results_aborts_on_other_errors_even_when_skipping() {
+        let day = Date::from_ymd_opt(2024, 1, 1).unwrap();
+        let results = vec![(day, Err(Error::Generic("boom".to_string())))];
+
+        let result = collect_range_results(results, true);
+
+        assert!(result.is_err());
+    }
 }
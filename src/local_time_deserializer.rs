@@ -1,6 +1,4 @@
-use std::ops::Sub;
-
-use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime};
+use chrono::{DateTime, FixedOffset};
 use serde::{self, Deserialize, Deserializer};
 
 const FORMAT: &'static str = "%Y-%m-%dT%H:%M:%S%z";
@@ -11,15 +9,34 @@ where
 {
     let s = String::deserialize(deserializer)?;
 
-    // Get the timezone offset by finding the substring following "+"
-    let tz_offset_start = s.find('+').unwrap() + 1;
-    let tz_offset: i32 = s.get(tz_offset_start..tz_offset_start + 2).unwrap().parse().unwrap();
+    // hvakosterstrommen returns colon-separated RFC3339 offsets (e.g. "+01:00"), which chrono
+    // parses correctly, offset and all. Fall back to the colon-less "%z" form for older payloads.
+    DateTime::parse_from_rfc3339(&s).or_else(|_| DateTime::parse_from_str(&s, FORMAT)).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize")] DateTime<FixedOffset>);
+
+    #[test]
+    fn parses_colon_separated_rfc3339_offset() {
+        let Wrapper(dt) = serde_json::from_str(r#""2024-01-31T00:00:00+01:00""#).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2024-01-31T00:00:00+01:00");
+    }
 
-    let dt = NaiveDateTime::parse_from_str(&s, FORMAT).map_err(serde::de::Error::custom)?;
-    let hour = 3600;
-    let tz = FixedOffset::east_opt(tz_offset * hour).unwrap();
+    #[test]
+    fn falls_back_to_colonless_offset() {
+        let Wrapper(dt) = serde_json::from_str(r#""2024-01-31T00:00:00+0100""#).unwrap();
+        assert_eq!(dt.offset().local_minus_utc(), 3600);
+    }
 
-    // Subtract the offset because parsing ignores timezone
-    let offset_delta = Duration::hours(tz_offset as i64);
-    Ok(DateTime::<FixedOffset>::from_naive_utc_and_offset(dt, tz).sub(offset_delta))
+    #[test]
+    fn rejects_unparseable_timestamps() {
+        let result: Result<Wrapper, _> = serde_json::from_str(r#""not-a-timestamp""#);
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,9 @@
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use serde::{self, Serializer};
+
+pub fn serialize<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.to_rfc3339_opts(SecondsFormat::Secs, false))
+}
@@ -1,7 +1,9 @@
+pub mod daily_prices;
 pub mod date;
 pub mod hourly_price;
 pub mod price_region;
 
+pub use daily_prices::{DailyPrices, DayKind};
 pub use date::Date;
 pub use hourly_price::HourlyPrice;
 pub use price_region::PriceRegion;
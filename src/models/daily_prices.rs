@@ -0,0 +1,175 @@
+use std::ops::Deref;
+
+use crate::models::HourlyPrice;
+
+/// Describes how a [`DailyPrices`] compares to an ordinary 24-hour day.
+///
+/// Because prices are keyed to local wall-clock time in the Europe/Oslo zone, the two
+/// days each year on which the clock changes do not contain exactly 24 hourly prices.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DayKind {
+    /// A day with 24 hourly prices, one per wall-clock hour.
+    Normal,
+    /// The spring-forward day (last Sunday of March), missing the 02:00 hour, for 23 entries.
+    ShortDay,
+    /// The fall-back day (last Sunday of October), with the 02:00 hour occurring twice, for 25 entries.
+    LongDay,
+}
+
+/// A day's worth of [`HourlyPrice`]s, as returned by the API.
+///
+/// This preserves the raw vector exactly as received. On the two Norwegian DST transition
+/// days the vector does not contain 24 entries: [`DailyPrices::kind`] reports which kind of
+/// day this is, and [`DailyPrices::by_local_hour`] maps a wall-clock hour to the entry or
+/// entries that cover it, accounting for the missing or doubled hour.
+///
+/// `DailyPrices` derefs to `[HourlyPrice]`, so existing code iterating or indexing the raw
+/// vector keeps working unchanged.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct DailyPrices {
+    prices: Vec<HourlyPrice>,
+}
+
+impl DailyPrices {
+    /// Wraps a vector of hourly prices, as returned by the API for a single day.
+    pub fn new(prices: Vec<HourlyPrice>) -> Self {
+        DailyPrices { prices }
+    }
+
+    /// The number of hourly prices in this day. Usually 24, but 23 or 25 on DST transition days.
+    pub fn len(&self) -> usize {
+        self.prices.len()
+    }
+
+    /// Whether this day has no prices at all.
+    pub fn is_empty(&self) -> bool {
+        self.prices.is_empty()
+    }
+
+    /// Determines whether this is a normal, short, or long day from the number of entries.
+    ///
+    /// Note this is *not* derived by comparing adjacent entries' `time_start`/`time_end`: on
+    /// both DST transition days, every pair of adjacent entries is still back-to-back in
+    /// absolute time (e.g. `01:00+01:00`-`02:00+01:00` is immediately followed by
+    /// `03:00+02:00`, the same instant), so such a comparison can never detect the gap or
+    /// overlap between wall-clock labels.
+    pub fn kind(&self) -> DayKind {
+        match self.prices.len() {
+            23 => DayKind::ShortDay,
+            25 => DayKind::LongDay,
+            _ => DayKind::Normal,
+        }
+    }
+
+    /// Returns the entries covering the given wall-clock hour (0-23).
+    ///
+    /// On a [`DayKind::Normal`] or [`DayKind::ShortDay`] this is at most one entry; on
+    /// [`DayKind::ShortDay`] the missing hour (02:00) returns an empty slice. On a
+    /// [`DayKind::LongDay`] the doubled hour (02:00) returns both of its entries.
+    pub fn by_local_hour(&self, hour: u32) -> &[HourlyPrice] {
+        let empty: &[HourlyPrice] = &[];
+        match self.kind() {
+            DayKind::Normal => self.prices.get(hour as usize..=hour as usize).unwrap_or(empty),
+            DayKind::ShortDay => {
+                if hour == 2 {
+                    return empty;
+                }
+                let index = if hour < 2 { hour as usize } else { hour as usize - 1 };
+                self.prices.get(index..=index).unwrap_or(empty)
+            }
+            DayKind::LongDay => {
+                if hour < 2 {
+                    self.prices.get(hour as usize..=hour as usize).unwrap_or(empty)
+                } else if hour == 2 {
+                    self.prices.get(2..=3).unwrap_or(empty)
+                } else {
+                    let index = hour as usize + 1;
+                    self.prices.get(index..=index).unwrap_or(empty)
+                }
+            }
+        }
+    }
+
+    /// Consumes this wrapper, returning the raw vector of hourly prices.
+    pub fn into_inner(self) -> Vec<HourlyPrice> {
+        self.prices
+    }
+}
+
+impl Deref for DailyPrices {
+    type Target = [HourlyPrice];
+
+    fn deref(&self) -> &Self::Target {
+        &self.prices
+    }
+}
+
+impl From<Vec<HourlyPrice>> for DailyPrices {
+    fn from(prices: Vec<HourlyPrice>) -> Self {
+        DailyPrices::new(prices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    // The exact instants don't matter for `kind`/`by_local_hour` (both are index-driven), so
+    // these just need a plausible, strictly increasing `time_start`/`time_end` per entry.
+    fn fixture(len: usize) -> Vec<HourlyPrice> {
+        let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+        let base = offset.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..len)
+            .map(|i| HourlyPrice {
+                nok_per_kwh: i as f64,
+                eur_per_kwh: 0.0,
+                exr: 0.0,
+                time_start: base + Duration::hours(i as i64),
+                time_end: base + Duration::hours(i as i64 + 1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kind_is_normal_for_24_entries() {
+        assert_eq!(DailyPrices::new(fixture(24)).kind(), DayKind::Normal);
+    }
+
+    #[test]
+    fn kind_is_short_day_for_23_entries() {
+        assert_eq!(DailyPrices::new(fixture(23)).kind(), DayKind::ShortDay);
+    }
+
+    #[test]
+    fn kind_is_long_day_for_25_entries() {
+        assert_eq!(DailyPrices::new(fixture(25)).kind(), DayKind::LongDay);
+    }
+
+    #[test]
+    fn by_local_hour_on_normal_day_is_a_direct_index() {
+        let daily = DailyPrices::new(fixture(24));
+        assert_eq!(daily.by_local_hour(5)[0].nok_per_kwh, 5.0);
+    }
+
+    #[test]
+    fn by_local_hour_on_short_day_skips_the_missing_hour() {
+        let daily = DailyPrices::new(fixture(23));
+        assert!(daily.by_local_hour(2).is_empty());
+        // Entries 0 and 1 are hours 00:00 and 01:00; entry 2 onward are hours 03:00 onward.
+        assert_eq!(daily.by_local_hour(0)[0].nok_per_kwh, 0.0);
+        assert_eq!(daily.by_local_hour(1)[0].nok_per_kwh, 1.0);
+        assert_eq!(daily.by_local_hour(3)[0].nok_per_kwh, 2.0);
+        assert_eq!(daily.by_local_hour(23)[0].nok_per_kwh, 22.0);
+    }
+
+    #[test]
+    fn by_local_hour_on_long_day_returns_both_doubled_entries() {
+        let daily = DailyPrices::new(fixture(25));
+        let doubled = daily.by_local_hour(2);
+        assert_eq!(doubled.len(), 2);
+        assert_eq!(doubled[0].nok_per_kwh, 2.0);
+        assert_eq!(doubled[1].nok_per_kwh, 3.0);
+        assert_eq!(daily.by_local_hour(23)[0].nok_per_kwh, 24.0);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::local_time_deserializer;
+use crate::local_time_serializer;
 use chrono::{DateTime, FixedOffset};
 use std::cmp::Ordering;
 
@@ -9,23 +10,27 @@ use std::cmp::Ordering;
 /// Hence, prices may vary slightly from official prices in NOK found at e.g. Nord Pool.
 /// The prices are not including VAT.
 ///
+/// Deriving `Serialize` alongside `Deserialize` means a fetched `HourlyPrice` round-trips
+/// back into the same JSON shape the API returns, which is handy for caching responses to
+/// disk or writing fixtures.
+///
 /// [`ENTSO-E`]: https://transparency.entsoe.eu/
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct HourlyPrice {
     /// The price per kWh in NOK, calculated using attribute `exr`
-    #[serde(rename(deserialize = "NOK_per_kWh"))]
+    #[serde(rename = "NOK_per_kWh")]
     pub nok_per_kwh: f64,
     /// The price per kWh in EUR
-    #[serde(rename(deserialize = "EUR_per_kWh"))]
+    #[serde(rename = "EUR_per_kWh")]
     pub eur_per_kwh: f64,
     /// The exchange rate from Norges Bank used to calculate `nok_per_kwh`
-    #[serde(rename(deserialize = "EXR"))]
+    #[serde(rename = "EXR")]
     pub exr: f64,
     /// The time this price is valid from
-    #[serde(with = "local_time_deserializer")]
+    #[serde(serialize_with = "local_time_serializer::serialize", deserialize_with = "local_time_deserializer::deserialize")]
     pub time_start: DateTime<FixedOffset>,
     /// The time this price is valid until
-    #[serde(with = "local_time_deserializer")]
+    #[serde(serialize_with = "local_time_serializer::serialize", deserialize_with = "local_time_deserializer::deserialize")]
     pub time_end: DateTime<FixedOffset>,
 }
 
@@ -34,3 +39,79 @@ impl PartialOrd for HourlyPrice {
         self.nok_per_kwh.partial_cmp(&other.nok_per_kwh)
     }
 }
+
+#[cfg(feature = "timezone")]
+impl HourlyPrice {
+    /// `time_start` converted into genuine Europe/Oslo wall-clock time.
+    ///
+    /// Unlike the `FixedOffset` on `time_start`, this resolves through the IANA tz database,
+    /// so it stays correct across the winter/summer DST boundary rather than trusting whatever
+    /// offset happened to be baked into this particular payload.
+    pub fn local_start(&self) -> DateTime<chrono_tz::Tz> {
+        self.time_start.with_timezone(&chrono_tz::Europe::Oslo)
+    }
+
+    /// `time_end` converted into genuine Europe/Oslo wall-clock time. See [`HourlyPrice::local_start`].
+    pub fn local_end(&self) -> DateTime<chrono_tz::Tz> {
+        self.time_end.with_timezone(&chrono_tz::Europe::Oslo)
+    }
+
+    /// The Europe/Oslo wall-clock hour (0-23) this price starts at.
+    pub fn local_hour(&self) -> u32 {
+        use chrono::Timelike;
+        self.local_start().hour()
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_the_apis_json_shape() {
+        let json = r#"{
+            "NOK_per_kWh": 1.2345,
+            "EUR_per_kWh": 0.1,
+            "EXR": 11.5,
+            "time_start": "2024-01-31T00:00:00+01:00",
+            "time_end": "2024-01-31T01:00:00+01:00"
+        }"#;
+
+        let price: HourlyPrice = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_string(&price).unwrap();
+        let round_tripped: HourlyPrice = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(price, round_tripped);
+        assert!(serialized.contains("\"NOK_per_kWh\":1.2345"));
+        assert!(serialized.contains("\"time_start\":\"2024-01-31T00:00:00+01:00\""));
+    }
+}
+
+#[cfg(all(test, feature = "timezone"))]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    fn price(time_start: &str, time_end: &str) -> HourlyPrice {
+        HourlyPrice {
+            nok_per_kwh: 0.0,
+            eur_per_kwh: 0.0,
+            exr: 0.0,
+            time_start: DateTime::parse_from_rfc3339(time_start).unwrap(),
+            time_end: DateTime::parse_from_rfc3339(time_end).unwrap(),
+        }
+    }
+
+    #[test]
+    fn local_hour_matches_oslo_wall_clock_in_winter() {
+        let price = price("2024-01-31T13:00:00+01:00", "2024-01-31T14:00:00+01:00");
+        assert_eq!(price.local_hour(), 13);
+    }
+
+    #[test]
+    fn local_start_resolves_through_the_tz_database_across_dst() {
+        // A UTC instant that is 14:00 in Oslo's summer (CEST, UTC+2) offset.
+        let price = price("2024-07-14T12:00:00Z", "2024-07-14T13:00:00Z");
+        assert_eq!(price.local_start().hour(), 14);
+    }
+}
@@ -0,0 +1,108 @@
+//! Helpers for answering "when is the cheapest time to run this" over a day's prices.
+
+use crate::HourlyPrice;
+
+/// Finds the contiguous run of `hours` entries in `prices` with the lowest total `nok_per_kwh`.
+///
+/// Useful for scheduling something like a dishwasher or EV charger that needs a fixed number
+/// of consecutive hours. Returns `None` if `hours` is `0` or greater than `prices.len()`, so it
+/// works unchanged on the 23- and 25-entry DST transition days.
+pub fn cheapest_window(prices: &[HourlyPrice], hours: usize) -> Option<&[HourlyPrice]> {
+    if hours == 0 || hours > prices.len() {
+        return None;
+    }
+
+    let mut sum: f64 = prices[..hours].iter().map(|price| price.nok_per_kwh).sum();
+    let mut cheapest_sum = sum;
+    let mut cheapest_start = 0;
+
+    for start in 1..=(prices.len() - hours) {
+        sum += prices[start + hours - 1].nok_per_kwh - prices[start - 1].nok_per_kwh;
+        if sum < cheapest_sum {
+            cheapest_sum = sum;
+            cheapest_start = start;
+        }
+    }
+
+    Some(&prices[cheapest_start..cheapest_start + hours])
+}
+
+/// Returns the `n` individually cheapest entries in `prices`, sorted ascending by `nok_per_kwh`.
+///
+/// Unlike [`cheapest_window`], the returned hours need not be consecutive.
+pub fn cheapest_hours(prices: &[HourlyPrice], n: usize) -> Vec<&HourlyPrice> {
+    let mut sorted: Vec<&HourlyPrice> = prices.iter().collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    sorted.truncate(n);
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, FixedOffset, TimeZone};
+
+    fn fixture(values: &[f64]) -> Vec<HourlyPrice> {
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let base = offset.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &nok_per_kwh)| HourlyPrice {
+                nok_per_kwh,
+                eur_per_kwh: 0.0,
+                exr: 0.0,
+                time_start: base + Duration::hours(i as i64),
+                time_end: base + Duration::hours(i as i64 + 1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cheapest_window_finds_the_lowest_sum_consecutive_block() {
+        let prices = fixture(&[5.0, 1.0, 1.0, 5.0, 5.0]);
+        let window = cheapest_window(&prices, 2).unwrap();
+        let window_prices: Vec<f64> = window.iter().map(|p| p.nok_per_kwh).collect();
+        assert_eq!(window_prices, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn cheapest_window_returns_none_for_zero_hours() {
+        let prices = fixture(&[1.0, 2.0, 3.0]);
+        assert!(cheapest_window(&prices, 0).is_none());
+    }
+
+    #[test]
+    fn cheapest_window_returns_none_when_hours_exceeds_len() {
+        let prices = fixture(&[1.0, 2.0, 3.0]);
+        assert!(cheapest_window(&prices, 4).is_none());
+    }
+
+    #[test]
+    fn cheapest_window_respects_a_23_entry_short_day() {
+        let prices = fixture(&[1.0; 23]);
+        assert!(cheapest_window(&prices, 23).is_some());
+        assert!(cheapest_window(&prices, 24).is_none());
+    }
+
+    #[test]
+    fn cheapest_window_respects_a_25_entry_long_day() {
+        let prices = fixture(&[1.0; 25]);
+        assert!(cheapest_window(&prices, 25).is_some());
+        assert!(cheapest_window(&prices, 26).is_none());
+    }
+
+    #[test]
+    fn cheapest_hours_returns_the_n_lowest_sorted_ascending() {
+        let prices = fixture(&[3.0, 1.0, 2.0]);
+        let hours = cheapest_hours(&prices, 2);
+        let values: Vec<f64> = hours.iter().map(|p| p.nok_per_kwh).collect();
+        assert_eq!(values, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn cheapest_hours_truncates_when_n_exceeds_len() {
+        let prices = fixture(&[3.0, 1.0]);
+        assert_eq!(cheapest_hours(&prices, 10).len(), 2);
+    }
+}